@@ -2,34 +2,48 @@ use std::rc::Rc;
 use std::cell::RefCell;
 use std::sync::Arc;
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use rand::{SeedableRng, XorShiftRng, Rng};
-use sfml::graphics::RenderWindow;
+use sfml::graphics::{RenderTarget, RenderTexture, RenderWindow};
 use na::{Pnt2, Pnt3, Iso2};
 use na;
 use nphysics2d::object::{WorldObject, RigidBodyHandle, SensorHandle};
 use ncollide::inspection::Repr2;
 use ncollide::shape;
+use ncollide::support_map::SupportMap;
 use camera::Camera;
 use objects::{SceneNode, Ball, Box, Lines, Segment};
 
+/// Number of support-map samples used to approximate the outline of a generic convex shape.
+const SUPPORT_MAP_SAMPLES: usize = 64;
+
+/// Minimum squared distance between two consecutive sampled points below which the
+/// second one is considered a duplicate and dropped (collapses flat faces).
+const SUPPORT_MAP_EPSILON_SQ: f32 = 1.0e-6;
+
 pub type GraphicsManagerHandle = Rc<RefCell<GraphicsManager<'static>>>;
 
 pub struct GraphicsManager<'a> {
     // NOTE: sensors and rigid bodies are not on the same hashmap because we want do draw sensors
     // after all the rigid bodies.
-    rand:      XorShiftRng,
-    rb2sn:     HashMap<usize, Vec<SceneNode<'a>>>,
-    s2sn:      HashMap<usize, Vec<SceneNode<'a>>>,
-    obj2color: HashMap<usize, Pnt3<u8>>
+    rand:          XorShiftRng,
+    rb2sn:         HashMap<usize, Vec<SceneNode<'a>>>,
+    s2sn:          HashMap<usize, Vec<SceneNode<'a>>>,
+    obj2color:     HashMap<usize, Pnt3<u8>>,
+    recording_dir: Option<PathBuf>,
+    frame_count:   usize
 }
 
 impl<'a> GraphicsManager<'a> {
     pub fn new() -> GraphicsManager<'a> {
         GraphicsManager {
-            rand:      SeedableRng::from_seed([0, 1, 2, 3]),
-            rb2sn:     HashMap::new(),
-            s2sn:      HashMap::new(),
-            obj2color: HashMap::new()
+            rand:          SeedableRng::from_seed([0, 1, 2, 3]),
+            rb2sn:         HashMap::new(),
+            s2sn:          HashMap::new(),
+            obj2color:     HashMap::new(),
+            recording_dir: None,
+            frame_count:   0
         }
     }
 
@@ -58,8 +72,6 @@ impl<'a> GraphicsManager<'a> {
         type Bl = shape::Ball2<f32>;
         type Cx = shape::Convex2<f32>;
         type Bo = shape::Cuboid2<f32>;
-        type Cy = shape::Cylinder2<f32>;
-        type Co = shape::Cone2<f32>;
         type Cm = shape::Compound2<f32>;
         type Ls = shape::Polyline2<f32>;
         type Se = shape::Segment2<f32>;
@@ -89,6 +101,11 @@ impl<'a> GraphicsManager<'a> {
         else if let Some(s) = repr.downcast_ref::<Ls>() {
             self.add_lines(object, delta, s, out)
         }
+        else if let Some(s) = shape.as_support_map() {
+            // Catch-all for any shape exposing ncollide's support function (e.g. Cylinder2,
+            // Cone2, or a future convex shape) that has no specialized rendering above.
+            self.add_support_map(object, delta, s, out)
+        }
         else {
             panic!("Not yet implemented.")
         }
@@ -129,6 +146,49 @@ impl<'a> GraphicsManager<'a> {
         out.push(SceneNode::LinesNode(Lines::new(object, delta, vs, is, color)))
     }
 
+    /// Renders any shape exposing a support function by sampling its boundary at regularly
+    /// spaced angles, so cones, cylinders, and future convex shapes don't need a dedicated
+    /// `add_*` method (see `add_ball`/`add_box` for the specialized, nicer-looking paths).
+    fn add_support_map(&mut self,
+                        object: WorldObject<f32>,
+                        delta:  Iso2<f32>,
+                        shape:  &SupportMap<Pnt2<f32>, Iso2<f32>>,
+                        out:    &mut Vec<SceneNode>) {
+        let color    = self.color_for_object(&object);
+        let margin   = object.borrow().margin();
+        let identity: Iso2<f32> = na::one();
+
+        let mut points = Vec::with_capacity(SUPPORT_MAP_SAMPLES);
+
+        for i in 0..SUPPORT_MAP_SAMPLES {
+            let theta = i as f32 * (2.0 * ::std::f32::consts::PI) / (SUPPORT_MAP_SAMPLES as f32);
+            let dir   = na::Vec2::new(theta.cos(), theta.sin());
+            let point = shape.support_point(&identity, &dir) + dir * margin;
+
+            // Drop consecutive near-duplicates: every sample facing a flat face returns the same
+            // vertex, so the face collapses to its two end points.
+            if points.last().map_or(true, |p: &Pnt2<f32>| na::sqdist(p, &point) > SUPPORT_MAP_EPSILON_SQ) {
+                points.push(point);
+            }
+        }
+
+        if points.len() > 1 && na::sqdist(&points[0], &points[points.len() - 1]) <= SUPPORT_MAP_EPSILON_SQ {
+            points.pop();
+        }
+
+        if points.len() < 3 {
+            // Degenerate shape: the samples collapsed onto fewer than three distinct points
+            // (a single point or a segment), so there is no outline to draw.
+            return;
+        }
+
+        let limit = points.len();
+        let vs = Arc::new(points);
+        let is = Arc::new((0..limit).map(|i| Pnt2::new(i, (i + 1) % limit)).collect());
+
+        out.push(SceneNode::LinesNode(Lines::new(object, delta, vs, is, color)))
+    }
+
     fn add_lines(&mut self,
                  object: WorldObject<f32>,
                  delta:  Iso2<f32>,
@@ -178,7 +238,13 @@ impl<'a> GraphicsManager<'a> {
     }
 
     pub fn draw(&mut self, rw: &mut RenderWindow, c: &Camera) {
-        c.activate_scene(rw);
+        self.draw_on(rw, c)
+    }
+
+    /// Draws the scene on any `RenderTarget`, be it a live `RenderWindow` or an offscreen
+    /// `RenderTexture`. Both the windowed and the headless recording paths go through this.
+    fn draw_on<RT: RenderTarget>(&mut self, rt: &mut RT, c: &Camera) {
+        c.activate_scene(rt);
 
         for (_, ns) in self.rb2sn.iter_mut().chain(self.s2sn.iter_mut()) {
             for n in ns.iter_mut() {
@@ -194,15 +260,59 @@ impl<'a> GraphicsManager<'a> {
         for (_, ns) in self.rb2sn.iter_mut().chain(self.s2sn.iter_mut()) {
             for n in ns.iter_mut() {
                 match *n {
-                    SceneNode::BoxNode(ref n)     => n.draw(rw),
-                    SceneNode::BallNode(ref n)    => n.draw(rw),
-                    SceneNode::LinesNode(ref n)   => n.draw(rw),
-                    SceneNode::SegmentNode(ref n) => n.draw(rw),
+                    SceneNode::BoxNode(ref n)     => n.draw(rt),
+                    SceneNode::BallNode(ref n)    => n.draw(rt),
+                    SceneNode::LinesNode(ref n)   => n.draw(rt),
+                    SceneNode::SegmentNode(ref n) => n.draw(rt),
                 }
             }
         }
 
-        c.activate_ui(rw);
+        c.activate_ui(rt);
+    }
+
+    /// Renders the current scene offscreen and writes it out as a single PNG at `path`.
+    pub fn capture_frame(&mut self, width: u32, height: u32, c: &Camera, path: &Path) {
+        let mut texture = RenderTexture::new(width, height, false)
+            .expect("failed to create the offscreen render texture");
+
+        self.draw_on(&mut texture, c);
+        texture.display();
+
+        let image = texture.texture()
+            .copy_to_image()
+            .expect("failed to read back the offscreen render texture");
+
+        if !image.save_to_file(path.to_str().expect("capture path must be valid UTF-8")) {
+            panic!("failed to save captured frame to {}", path.display());
+        }
+    }
+
+    /// Starts recording a numbered PNG sequence into `dir`, one frame per `record_frame` call.
+    /// This lets a simulation be turned into a deterministic animation clip with no visible
+    /// window, e.g. on a CI machine.
+    pub fn start_recording<P: AsRef<Path>>(&mut self, dir: P) {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir).expect("failed to create the recording directory");
+
+        self.recording_dir = Some(dir);
+        self.frame_count   = 0;
+    }
+
+    /// Stops the current recording, if any.
+    pub fn stop_recording(&mut self) {
+        self.recording_dir = None;
+        self.frame_count   = 0;
+    }
+
+    /// Captures the current scene as the next frame of the active recording. No-op if
+    /// `start_recording` hasn't been called.
+    pub fn record_frame(&mut self, width: u32, height: u32, c: &Camera) {
+        if let Some(dir) = self.recording_dir.clone() {
+            let path = dir.join(format!("frame_{:06}.png", self.frame_count));
+            self.capture_frame(width, height, c, &path);
+            self.frame_count += 1;
+        }
     }
 
     fn set_color(&mut self, key: usize, color: Pnt3<f32>) {
@@ -0,0 +1,168 @@
+use na::{Isometry3, Real, Vector3};
+
+use joint::Joint;
+use solver::{ConstraintSet, IntegrationParameters};
+use object::{Multibody, MultibodyLinkRef};
+use math::{JacobianSliceMut, Velocity};
+
+/// A joint that composes an ordered list of sub-joints, summing their degrees of freedom.
+///
+/// This lets users build arbitrary multi-DOF joints (e.g. prismatic + prismatic + revolute)
+/// without writing a new `Joint` implementation: every method below just walks the children in
+/// order, forwarding to each while tracking the running DOF offset it owns.
+pub struct CompositeJoint<N: Real> {
+    joints: Vec<Box<Joint<N>>>,
+}
+
+impl<N: Real> CompositeJoint<N> {
+    /// Creates a composite joint chaining `joints` in order, from the closest to the parent
+    /// body to the closest to the child body.
+    pub fn new(joints: Vec<Box<Joint<N>>>) -> Self {
+        CompositeJoint { joints }
+    }
+}
+
+impl<N: Real> Joint<N> for CompositeJoint<N> {
+    #[inline]
+    fn ndofs(&self) -> usize {
+        self.joints.iter().map(|joint| joint.ndofs()).sum()
+    }
+
+    fn body_to_parent(&self, parent_shift: &Vector3<N>, body_shift: &Vector3<N>) -> Isometry3<N> {
+        if self.joints.is_empty() {
+            return Isometry3::identity();
+        }
+
+        // The sub-joints share one physical anchor, so the anchor shifts must be baked in
+        // exactly once rather than once per child: `parent_shift` goes on the first child,
+        // `body_shift` on the last, and every child in between gets a zero shift and
+        // contributes local motion only.
+        let zero = Vector3::zeros();
+        let last = self.joints.len() - 1;
+        let mut result = Isometry3::identity();
+
+        for (i, joint) in self.joints.iter().enumerate() {
+            let parent_shift = if i == 0 { parent_shift } else { &zero };
+            let body_shift = if i == last { body_shift } else { &zero };
+
+            result = result * joint.body_to_parent(parent_shift, body_shift);
+        }
+
+        result
+    }
+
+    fn update_jacobians(&mut self, body_shift: &Vector3<N>, vels: &[N]) {
+        let mut offset = 0;
+
+        for joint in &mut self.joints {
+            joint.update_jacobians(body_shift, &vels[offset..offset + joint.ndofs()]);
+            offset += joint.ndofs();
+        }
+    }
+
+    fn jacobian(&self, transform: &Isometry3<N>, out: &mut JacobianSliceMut<N>) {
+        let mut offset = 0;
+
+        for joint in &self.joints {
+            joint.jacobian(transform, &mut out.columns_mut(offset, joint.ndofs()));
+            offset += joint.ndofs();
+        }
+    }
+
+    fn jacobian_dot(&self, transform: &Isometry3<N>, out: &mut JacobianSliceMut<N>) {
+        // NOTE: as with `PinSlotJoint`, cross-coupling `jacobian_dot` terms between independent
+        // sub-joints are treated as zero. Each child only fills in its own columns.
+        let mut offset = 0;
+
+        for joint in &self.joints {
+            joint.jacobian_dot(transform, &mut out.columns_mut(offset, joint.ndofs()));
+            offset += joint.ndofs();
+        }
+    }
+
+    fn jacobian_dot_veldiff_mul_coordinates(
+        &self,
+        transform: &Isometry3<N>,
+        vels: &[N],
+        out: &mut JacobianSliceMut<N>,
+    ) {
+        let mut offset = 0;
+
+        for joint in &self.joints {
+            joint.jacobian_dot_veldiff_mul_coordinates(
+                transform,
+                &vels[offset..offset + joint.ndofs()],
+                &mut out.columns_mut(offset, joint.ndofs()),
+            );
+            offset += joint.ndofs();
+        }
+    }
+
+    fn jacobian_mul_coordinates(&self, vels: &[N]) -> Velocity<N> {
+        let mut offset = 0;
+        let mut result = Velocity::zero();
+
+        for joint in &self.joints {
+            result = result + joint.jacobian_mul_coordinates(&vels[offset..offset + joint.ndofs()]);
+            offset += joint.ndofs();
+        }
+
+        result
+    }
+
+    fn jacobian_dot_mul_coordinates(&self, vels: &[N]) -> Velocity<N> {
+        // NOTE: same approximation as `jacobian_dot`, the cross-coupling terms are zero.
+        let mut offset = 0;
+        let mut result = Velocity::zero();
+
+        for joint in &self.joints {
+            result = result + joint.jacobian_dot_mul_coordinates(&vels[offset..offset + joint.ndofs()]);
+            offset += joint.ndofs();
+        }
+
+        result
+    }
+
+    fn apply_displacement(&mut self, params: &IntegrationParameters<N>, vels: &[N]) {
+        let mut offset = 0;
+
+        for joint in &mut self.joints {
+            joint.apply_displacement(params, &vels[offset..offset + joint.ndofs()]);
+            offset += joint.ndofs();
+        }
+    }
+
+    fn nconstraints(&self) -> usize {
+        self.joints.iter().map(|joint| joint.nconstraints()).sum()
+    }
+
+    fn build_constraints(
+        &self,
+        params: &IntegrationParameters<N>,
+        mb: &Multibody<N>,
+        link: &MultibodyLinkRef<N>,
+        assembly_id: usize,
+        dof_id: usize,
+        ext_vels: &[N],
+        ground_jacobian_id: &mut usize,
+        jacobians: &mut [N],
+        vel_constraints: &mut ConstraintSet<N>,
+    ) {
+        let mut offset = dof_id;
+
+        for joint in &self.joints {
+            joint.build_constraints(
+                params,
+                mb,
+                link,
+                assembly_id,
+                offset,
+                ext_vels,
+                ground_jacobian_id,
+                jacobians,
+                vel_constraints,
+            );
+            offset += joint.ndofs();
+        }
+    }
+}
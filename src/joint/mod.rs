@@ -0,0 +1,11 @@
+pub use self::pin_slot_joint::PinSlotJoint;
+pub use self::composite_joint::CompositeJoint;
+pub use self::cylindrical_joint::CylindricalJoint;
+pub use self::universal_joint::UniversalJoint;
+pub use self::planar_joint::PlanarJoint;
+
+mod pin_slot_joint;
+mod composite_joint;
+mod cylindrical_joint;
+mod universal_joint;
+mod planar_joint;
@@ -0,0 +1,139 @@
+use na::{Isometry3, Real, Unit, Vector3};
+
+use joint::{Joint, RevoluteJoint};
+use solver::{ConstraintSet, IntegrationParameters};
+use object::{Multibody, MultibodyLinkRef};
+use math::{JacobianSliceMut, Velocity};
+
+/// A U-joint coupling two revolute joints spinning about orthogonal axes.
+#[derive(Copy, Clone, Debug)]
+pub struct UniversalJoint<N: Real> {
+    revo1: RevoluteJoint<N>,
+    revo2: RevoluteJoint<N>,
+}
+
+impl<N: Real> UniversalJoint<N> {
+    pub fn new(axis1: Unit<Vector3<N>>, axis2: Unit<Vector3<N>>, angle1: N, angle2: N) -> Self {
+        let revo1 = RevoluteJoint::new(axis1, angle1);
+        let revo2 = RevoluteJoint::new(axis2, angle2);
+
+        UniversalJoint { revo1, revo2 }
+    }
+
+    pub fn angle1(&self) -> N {
+        self.revo1.angle()
+    }
+
+    pub fn angle2(&self) -> N {
+        self.revo2.angle()
+    }
+}
+
+impl<N: Real> Joint<N> for UniversalJoint<N> {
+    #[inline]
+    fn ndofs(&self) -> usize {
+        2
+    }
+
+    fn body_to_parent(&self, parent_shift: &Vector3<N>, body_shift: &Vector3<N>) -> Isometry3<N> {
+        // The anchor shifts are baked in exactly once: `parent_shift` by `revo1` (so it is not
+        // rotated by `revo1`'s angle) and `body_shift` by `revo2`.
+        let zero = Vector3::zeros();
+        self.revo1.body_to_parent(parent_shift, &zero) * self.revo2.body_to_parent(&zero, body_shift)
+    }
+
+    fn update_jacobians(&mut self, body_shift: &Vector3<N>, vels: &[N]) {
+        self.revo1.update_jacobians(body_shift, &[vels[0]]);
+        self.revo2.update_jacobians(body_shift, &[vels[1]]);
+    }
+
+    fn jacobian(&self, transform: &Isometry3<N>, out: &mut JacobianSliceMut<N>) {
+        self.revo1.jacobian(transform, &mut out.columns_mut(0, 1));
+        self.revo2.jacobian(transform, &mut out.columns_mut(1, 1));
+    }
+
+    fn jacobian_dot(&self, transform: &Isometry3<N>, out: &mut JacobianSliceMut<N>) {
+        self.revo1
+            .jacobian_dot(transform, &mut out.columns_mut(0, 1));
+        self.revo2
+            .jacobian_dot(transform, &mut out.columns_mut(1, 1));
+    }
+
+    fn jacobian_dot_veldiff_mul_coordinates(
+        &self,
+        transform: &Isometry3<N>,
+        vels: &[N],
+        out: &mut JacobianSliceMut<N>,
+    ) {
+        self.revo1.jacobian_dot_veldiff_mul_coordinates(
+            transform,
+            &[vels[0]],
+            &mut out.columns_mut(0, 1),
+        );
+        self.revo2.jacobian_dot_veldiff_mul_coordinates(
+            transform,
+            &[vels[1]],
+            &mut out.columns_mut(1, 1),
+        );
+    }
+
+    fn jacobian_mul_coordinates(&self, vels: &[N]) -> Velocity<N> {
+        self.revo1.jacobian_mul_coordinates(&[vels[0]])
+            + self.revo2.jacobian_mul_coordinates(&[vels[1]])
+    }
+
+    fn jacobian_dot_mul_coordinates(&self, vels: &[N]) -> Velocity<N> {
+        // NOTE: cross-coupling between the two independent revolute axes is treated as zero,
+        // the same approximation `PinSlotJoint` makes between its prismatic and revolute axes.
+        self.revo1.jacobian_dot_mul_coordinates(&[vels[0]])
+            + self.revo2.jacobian_dot_mul_coordinates(&[vels[1]])
+    }
+
+    fn apply_displacement(&mut self, params: &IntegrationParameters<N>, vels: &[N]) {
+        self.revo1.apply_displacement(params, &[vels[0]]);
+        self.revo2.apply_displacement(params, &[vels[1]]);
+    }
+
+    fn nconstraints(&self) -> usize {
+        self.revo1.nconstraints() + self.revo2.nconstraints()
+    }
+
+    fn build_constraints(
+        &self,
+        params: &IntegrationParameters<N>,
+        mb: &Multibody<N>,
+        link: &MultibodyLinkRef<N>,
+        assembly_id: usize,
+        dof_id: usize,
+        ext_vels: &[N],
+        ground_jacobian_id: &mut usize,
+        jacobians: &mut [N],
+        vel_constraints: &mut ConstraintSet<N>,
+    ) {
+        self.revo1.build_constraints(
+            params,
+            mb,
+            link,
+            assembly_id,
+            dof_id,
+            ext_vels,
+            ground_jacobian_id,
+            jacobians,
+            vel_constraints,
+        );
+        self.revo2.build_constraints(
+            params,
+            mb,
+            link,
+            assembly_id,
+            dof_id + 1,
+            ext_vels,
+            ground_jacobian_id,
+            jacobians,
+            vel_constraints,
+        );
+    }
+}
+
+revolute_motor_limit_methods_1!(UniversalJoint, revo1);
+revolute_motor_limit_methods_2!(UniversalJoint, revo2);